@@ -0,0 +1,131 @@
+use crate::ray::Ray;
+use nalgebra::{Point3, Vector3};
+
+pub struct HitRecord {
+    pub point: Point3<f64>,
+    pub normal: Vector3<f64>,
+    pub t: f64,
+    pub front_face: bool,
+}
+
+pub trait Hittable: Send + Sync {
+    fn hit(&self, ray: &Ray, t_min: f64, t_max: f64) -> Option<HitRecord>;
+}
+
+pub struct Sphere {
+    pub center: Point3<f64>,
+    pub radius: f64,
+}
+
+impl Hittable for Sphere {
+    fn hit(&self, ray: &Ray, t_min: f64, t_max: f64) -> Option<HitRecord> {
+        let oc = ray.origin - self.center;
+        let a = ray.direction.dot(&ray.direction);
+        let half_b = oc.dot(&ray.direction);
+        let c = oc.dot(&oc) - self.radius * self.radius;
+        let discriminant = half_b * half_b - a * c;
+        if discriminant < 0.0 {
+            return None;
+        }
+        let sqrtd = discriminant.sqrt();
+
+        let mut t = (-half_b - sqrtd) / a;
+        if t < t_min || t > t_max {
+            t = (-half_b + sqrtd) / a;
+            if t < t_min || t > t_max {
+                return None;
+            }
+        }
+
+        let point = ray.at(t);
+        let outward_normal = (point - self.center) / self.radius;
+        let front_face = ray.direction.dot(&outward_normal) < 0.0;
+
+        Some(HitRecord {
+            point,
+            normal: outward_normal,
+            t,
+            front_face,
+        })
+    }
+}
+
+#[derive(Default)]
+pub struct HittableList(pub Vec<Box<dyn Hittable>>);
+
+impl Hittable for HittableList {
+    fn hit(&self, ray: &Ray, t_min: f64, t_max: f64) -> Option<HitRecord> {
+        let mut closest_so_far = t_max;
+        let mut closest_hit = None;
+
+        for object in &self.0 {
+            if let Some(rec) = object.hit(ray, t_min, closest_so_far) {
+                closest_so_far = rec.t;
+                closest_hit = Some(rec);
+            }
+        }
+
+        closest_hit
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ray_through_center_hits_near_surface() {
+        let sphere = Sphere {
+            center: Point3::new(0.0, 0.0, -2.0),
+            radius: 1.0,
+        };
+        let ray = Ray::new(Point3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 0.0, -1.0));
+
+        let rec = sphere.hit(&ray, 0.0, f64::INFINITY).expect("ray through center should hit");
+
+        assert!((rec.t - 1.0).abs() < 1e-9);
+        assert!((rec.point - Point3::new(0.0, 0.0, -1.0)).norm() < 1e-9);
+        assert!(rec.front_face);
+    }
+
+    #[test]
+    fn ray_missing_sphere_returns_none() {
+        let sphere = Sphere {
+            center: Point3::new(0.0, 0.0, -2.0),
+            radius: 1.0,
+        };
+        let ray = Ray::new(Point3::new(5.0, 5.0, 0.0), Vector3::new(0.0, 0.0, -1.0));
+
+        assert!(sphere.hit(&ray, 0.0, f64::INFINITY).is_none());
+    }
+
+    #[test]
+    fn ray_from_inside_sphere_hits_far_wall_and_reports_back_face() {
+        let sphere = Sphere {
+            center: Point3::new(0.0, 0.0, 0.0),
+            radius: 1.0,
+        };
+        let ray = Ray::new(Point3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 0.0, -1.0));
+
+        let rec = sphere
+            .hit(&ray, 0.001, f64::INFINITY)
+            .expect("ray from center should hit the far wall");
+
+        assert!((rec.t - 1.0).abs() < 1e-9);
+        assert!(!rec.front_face);
+    }
+
+    #[test]
+    fn hit_picks_nearest_root_within_t_range() {
+        let sphere = Sphere {
+            center: Point3::new(0.0, 0.0, -5.0),
+            radius: 1.0,
+        };
+        let ray = Ray::new(Point3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 0.0, -1.0));
+
+        let rec = sphere.hit(&ray, 0.0, f64::INFINITY).expect("ray should hit");
+
+        // Near root is at t=4, far root at t=6 — hit() must prefer the near one.
+        assert!((rec.t - 4.0).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,79 @@
+use clap::ValueEnum;
+use nalgebra::Vector3;
+use rayon::prelude::*;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+pub type Color = Vector3<f32>;
+
+#[derive(Copy, Clone, Debug, ValueEnum)]
+pub enum OutputFormat {
+    Ppm,
+    Png,
+}
+
+pub struct Image {
+    pub width: u32,
+    pub height: u32,
+    pub data: Vec<Color>,
+}
+
+impl Image {
+    /// Computes every pixel in parallel via rayon, then hands back a fully
+    /// populated buffer in row-major order.
+    pub fn render<F>(width: u32, height: u32, shade: F) -> Self
+    where
+        F: Fn(u32, u32) -> Color + Sync,
+    {
+        let done = AtomicU32::new(0);
+
+        let data = (0..width * height)
+            .into_par_iter()
+            .map(|idx| {
+                let color = shade(idx % width, idx / width);
+                let finished = done.fetch_add(1, Ordering::Relaxed) + 1;
+                if finished.is_multiple_of(width) {
+                    eprint!("\rScanlines remaining: {} ", height - finished / width);
+                }
+                color
+            })
+            .collect();
+        eprint!("\r");
+
+        Self { width, height, data }
+    }
+
+    pub fn save(&self, path: &Path, format: OutputFormat) -> io::Result<()> {
+        match format {
+            OutputFormat::Ppm => self.write_ppm(BufWriter::new(File::create(path)?)),
+            OutputFormat::Png => self
+                .to_rgb_image()
+                .save_with_format(path, ::image::ImageFormat::Png)
+                .map_err(io::Error::other),
+        }
+    }
+
+    fn write_ppm(&self, mut writer: impl Write) -> io::Result<()> {
+        writeln!(writer, "P3\n{} {}\n255\n", self.width, self.height)?;
+        for color in &self.data {
+            let ir = (255.99 * color.x) as i32;
+            let ig = (255.99 * color.y) as i32;
+            let ib = (255.99 * color.z) as i32;
+            writeln!(writer, "{} {} {}", ir, ig, ib)?;
+        }
+        Ok(())
+    }
+
+    fn to_rgb_image(&self) -> ::image::RgbImage {
+        let mut img = ::image::RgbImage::new(self.width, self.height);
+        for (idx, color) in self.data.iter().enumerate() {
+            let x = idx as u32 % self.width;
+            let y = idx as u32 / self.width;
+            let channel = |c: f32| (c.clamp(0.0, 1.0) * 255.0) as u8;
+            img.put_pixel(x, y, ::image::Rgb([channel(color.x), channel(color.y), channel(color.z)]));
+        }
+        img
+    }
+}
@@ -1,39 +1,77 @@
+pub mod hittable;
+pub mod image;
 pub mod ray;
+pub mod scene;
 
-use nalgebra::Vector3;
+use clap::Parser;
+use hittable::Hittable;
+use image::{Color, Image, OutputFormat};
+use ray::Ray;
+use scene::Scene;
+use std::path::PathBuf;
 
-type Color = Vector3<f32>;
+/// A small ray tracer driven by a plaintext scene description.
+#[derive(Parser)]
+struct Cli {
+    /// Path to the scene description file
+    scene: PathBuf,
 
+    /// Where to write the rendered image
+    #[arg(short, long, default_value = "image.ppm")]
+    output: PathBuf,
 
-// 使用 `cargo run > image.ppm`
-fn main() {
-    let img_width = 256;
-    let img_height = 256;
+    /// Output image format
+    #[arg(short, long, value_enum, default_value_t = OutputFormat::Ppm)]
+    format: OutputFormat,
 
-    println!("P3\n{} {}\n255\n", img_width, img_height);
-    for j in (0..img_height).rev() {
-        // 这个 \r 可以清空当前一行
-        eprint!("\rScanlines remaining: {} ", j);
+    /// Number of jittered samples per pixel (anti-aliasing)
+    #[arg(short, long, default_value_t = 1)]
+    samples: u32,
+}
 
-        for i in 0..img_width {
+fn main() {
+    let cli = Cli::parse();
+    let scene = scene::parse_scene(&cli.scene).expect("failed to parse scene file");
 
-            let r = i as f32 / (img_width - 1) as f32;
-            let g = j as f32 / (img_height - 1) as f32;
-            let b = 0.25f32;
+    let samples = cli.samples;
+    let image = Image::render(scene.img_width, scene.img_height, |i, j| {
+        if samples <= 1 {
+            let ray = primary_ray(&scene, i, j, 0.5, 0.5);
+            return shade(&scene, &ray);
+        }
 
-            let color = Color::new(r, g, b);
-            write_color(color);
+        let mut accum = Color::new(0.0, 0.0, 0.0);
+        for _ in 0..samples {
+            let du = rand::random::<f64>();
+            let dv = rand::random::<f64>();
+            let ray = primary_ray(&scene, i, j, du, dv);
+            accum += shade(&scene, &ray);
         }
-    }
-    // clear
-    eprint!("\r");                     
+        gamma_correct(accum / samples as f32)
+    });
+
+    image.save(&cli.output, cli.format).expect("failed to write image");
 }
 
+fn primary_ray(scene: &Scene, i: u32, j: u32, du: f64, dv: f64) -> Ray {
+    let cam = &scene.camera;
+    let img_width = scene.img_width as f64;
+    let img_height = scene.img_height as f64;
 
-fn write_color(color: Color) {
-    let ir = (255.99 * color.x) as i32;
-    let ig = (255.99 * color.y) as i32;
-    let ib = (255.99 * color.z) as i32;
+    let s = 2.0 * (i as f64 + du) / img_width - 1.0;
+    let t = 1.0 - 2.0 * (j as f64 + dv) / img_height;
 
-    println!("{} {} {}", ir, ig, ib);
+    let direction = -cam.w + s * cam.half_width * cam.u + t * cam.half_height * cam.v;
+    Ray::new(cam.eye, direction)
+}
+
+fn gamma_correct(color: Color) -> Color {
+    Color::new(color.x.sqrt(), color.y.sqrt(), color.z.sqrt())
+}
+
+fn shade(scene: &Scene, ray: &Ray) -> Color {
+    match scene.objects.hit(ray, 0.001, f64::INFINITY) {
+        Some(_rec) => Color::new(1.0, 1.0, 1.0),
+        None => scene.bkgcolor,
+    }
 }
@@ -1,19 +1,26 @@
-use nalgebra::{Point3, Vector3};
+use nalgebra::{Point3, Unit, Vector3};
 
-struct Ray<'a> {
-    origin: &'a Point3<f64>,
-    direction: &'a Vector3<f64>,
+pub struct Ray {
+    pub origin: Point3<f64>,
+    pub direction: Vector3<f64>,
 }
 
-impl<'a, 'b> Ray<'a>
-where
-    'b: 'a,
-{
-    fn new(origin: &'b Point3<f64>, direction: &'b Vector3<f64>) -> Self {
+impl Ray {
+    pub fn new(origin: Point3<f64>, direction: Vector3<f64>) -> Self {
         Self { origin, direction }
     }
 
-    fn at(&self, t: f64) -> Point3<f64> {
+    /// Ray from `a` toward `b`; direction is `b - a` (not normalized).
+    pub fn from_points(a: Point3<f64>, b: Point3<f64>) -> Self {
+        Self::new(a, b - a)
+    }
+
+    /// Ray from `a` toward `b` with a unit-length direction.
+    pub fn from_endpoints(a: Point3<f64>, b: Point3<f64>) -> Self {
+        Self::new(a, Unit::new_normalize(b - a).into_inner())
+    }
+
+    pub fn at(&self, t: f64) -> Point3<f64> {
         self.origin + t * self.direction
     }
 }
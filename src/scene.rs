@@ -0,0 +1,107 @@
+use crate::hittable::{HittableList, Sphere};
+use crate::image::Color;
+use nalgebra::{Point3, Vector3};
+use std::fs;
+use std::path::Path;
+
+pub struct Camera {
+    pub eye: Point3<f64>,
+    pub u: Vector3<f64>,
+    pub v: Vector3<f64>,
+    pub w: Vector3<f64>,
+    pub half_width: f64,
+    pub half_height: f64,
+}
+
+pub struct Scene {
+    pub camera: Camera,
+    pub img_width: u32,
+    pub img_height: u32,
+    pub bkgcolor: Color,
+    pub objects: HittableList,
+}
+
+pub fn parse_scene(path: &Path) -> Result<Scene, String> {
+    let text = fs::read_to_string(path).map_err(|e| format!("cannot read scene file {}: {}", path.display(), e))?;
+
+    let mut eye = None;
+    let mut viewdir = None;
+    let mut updir = None;
+    let mut hfov = None;
+    let mut imsize = None;
+    let mut bkgcolor = None;
+    let mut spheres: Vec<Box<dyn crate::hittable::Hittable>> = Vec::new();
+
+    for (lineno, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let tokens: Vec<&str> = line.split_whitespace().collect();
+        let keyword = tokens[0];
+        let nums: Result<Vec<f64>, _> = tokens[1..].iter().map(|t| t.parse::<f64>()).collect();
+        let nums = nums.map_err(|e| format!("{}:{}: {}", path.display(), lineno + 1, e))?;
+
+        let expected = match keyword {
+            "eye" | "viewdir" | "updir" | "bkgcolor" => 3,
+            "hfov" => 1,
+            "imsize" => 2,
+            "sphere" => 4,
+            other => return Err(format!("{}:{}: unknown keyword '{}'", path.display(), lineno + 1, other)),
+        };
+        if nums.len() != expected {
+            return Err(format!(
+                "{}:{}: '{}' expects {} value(s), got {}",
+                path.display(),
+                lineno + 1,
+                keyword,
+                expected,
+                nums.len()
+            ));
+        }
+
+        match keyword {
+            "eye" => eye = Some(Point3::new(nums[0], nums[1], nums[2])),
+            "viewdir" => viewdir = Some(Vector3::new(nums[0], nums[1], nums[2])),
+            "updir" => updir = Some(Vector3::new(nums[0], nums[1], nums[2])),
+            "hfov" => hfov = Some(nums[0]),
+            "imsize" => imsize = Some((nums[0] as u32, nums[1] as u32)),
+            "bkgcolor" => bkgcolor = Some(Color::new(nums[0] as f32, nums[1] as f32, nums[2] as f32)),
+            "sphere" => spheres.push(Box::new(Sphere {
+                center: Point3::new(nums[0], nums[1], nums[2]),
+                radius: nums[3],
+            })),
+            _ => unreachable!("unknown keywords are rejected above"),
+        }
+    }
+
+    let eye = eye.ok_or("scene missing 'eye'")?;
+    let viewdir = viewdir.ok_or("scene missing 'viewdir'")?;
+    let updir = updir.ok_or("scene missing 'updir'")?;
+    let hfov = hfov.ok_or("scene missing 'hfov'")?;
+    let (img_width, img_height) = imsize.ok_or("scene missing 'imsize'")?;
+    let bkgcolor = bkgcolor.ok_or("scene missing 'bkgcolor'")?;
+
+    let w = -viewdir.normalize();
+    let u = viewdir.cross(&updir).normalize();
+    let v = u.cross(&(-w));
+
+    let half_width = (hfov.to_radians() / 2.0).tan();
+    let half_height = half_width * img_height as f64 / img_width as f64;
+
+    Ok(Scene {
+        camera: Camera {
+            eye,
+            u,
+            v,
+            w,
+            half_width,
+            half_height,
+        },
+        img_width,
+        img_height,
+        bkgcolor,
+        objects: HittableList(spheres),
+    })
+}